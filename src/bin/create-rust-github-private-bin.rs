@@ -1,13 +1,23 @@
 use std::io::{stderr, stdout};
 
-use clap::Parser;
-use create_rust_github_repo::CreateRustGithubRepo;
+use create_rust_github_repo::{exit_code, CreateRustGithubRepo, Visibility};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    CreateRustGithubRepo::parse()
-        .repo_create_cmd("gh repo create --private {{name}}")
-        .project_init_cmd("cargo init --bin")
-        .run(&mut stdout(), &mut stderr(), None)
-        .await
+    let cmd = CreateRustGithubRepo::parse_with_profile()?
+        .visibility(Visibility::Private)
+        .project_init_cmd(["cargo init --bin".to_string()]);
+    let interrupt_handler = cmd.interrupt_handler();
+    tokio::spawn(async {
+        interrupt_handler.await;
+        std::process::exit(130);
+    });
+    let result = cmd.run(&mut stdout(), &mut stderr(), None).await;
+    if let Err(error) = &result {
+        if let Some(code) = exit_code(error) {
+            eprintln!("{error}");
+            std::process::exit(code);
+        }
+    }
+    result
 }
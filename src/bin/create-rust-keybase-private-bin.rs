@@ -1,12 +1,21 @@
 use std::io::{stderr, stdout};
 
-use clap::Parser;
-use create_rust_github_repo::{set_keybase_defaults, CreateRustGithubRepo};
+use create_rust_github_repo::{exit_code, set_keybase_defaults, CreateRustGithubRepo};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    set_keybase_defaults(CreateRustGithubRepo::parse())
-        .project_init_cmd("cargo init --bin")
-        .run(&mut stdout(), &mut stderr(), None)
-        .await
+    let cmd = set_keybase_defaults(CreateRustGithubRepo::parse_with_profile()?).project_init_cmd(["cargo init --bin".to_string()]);
+    let interrupt_handler = cmd.interrupt_handler();
+    tokio::spawn(async {
+        interrupt_handler.await;
+        std::process::exit(130);
+    });
+    let result = cmd.run(&mut stdout(), &mut stderr(), None).await;
+    if let Err(error) = &result {
+        if let Some(code) = exit_code(error) {
+            eprintln!("{error}");
+            std::process::exit(code);
+        }
+    }
+    result
 }